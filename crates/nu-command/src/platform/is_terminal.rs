@@ -1,10 +1,103 @@
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    span, Category, Example, PipelineData, ShellError, Signature, Type, Value,
+    record, Category, Example, PipelineData, Record, ShellError, Signature, Type, Value,
 };
 use std::io::IsTerminal as _;
 
+/// Check whether `stream` is connected to a terminal.
+///
+/// On Windows this also recognizes the named pipes used by mintty-based
+/// terminal emulators (MSYS2, Cygwin, Git Bash), which front the process
+/// with a pipe rather than a console handle and so are otherwise invisible
+/// to [`std::io::IsTerminal`].
+#[cfg(windows)]
+pub(crate) fn is_terminal<T: std::io::IsTerminal + std::os::windows::io::AsRawHandle>(
+    stream: &T,
+) -> bool {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Foundation::MAX_PATH;
+    use windows_sys::Win32::Storage::FileSystem::{
+        FileNameInfo, GetFileInformationByHandleEx, GetFileType, FILE_NAME_INFO, FILE_TYPE_PIPE,
+    };
+
+    let handle = stream.as_raw_handle() as isize;
+
+    // SAFETY: `handle` comes from `AsRawHandle` on a live stream, and the
+    // buffer passed to `GetFileInformationByHandleEx` is sized to hold a
+    // `FILE_NAME_INFO` plus a `MAX_PATH` UTF-16 file name, as required by
+    // that call.
+    unsafe {
+        if GetFileType(handle) != FILE_TYPE_PIPE {
+            return stream.is_terminal();
+        }
+
+        let mut name_info_bytes =
+            vec![0u8; std::mem::size_of::<FILE_NAME_INFO>() + MAX_PATH as usize * 2];
+        let result = GetFileInformationByHandleEx(
+            handle,
+            FileNameInfo,
+            name_info_bytes.as_mut_ptr().cast(),
+            name_info_bytes.len() as u32,
+        );
+        if result == 0 {
+            return false;
+        }
+
+        let name_info = &*(name_info_bytes.as_ptr() as *const FILE_NAME_INFO);
+        let name_len = (name_info.FileNameLength as usize) / 2;
+        let name_slice = std::slice::from_raw_parts(name_info.FileName.as_ptr(), name_len);
+        let name = String::from_utf16_lossy(name_slice);
+
+        is_msys_pty_name(&name)
+    }
+}
+
+/// Matches the pipe names mintty-based terminals (MSYS2, Cygwin) give their
+/// pseudo-terminal pipes, e.g. `\msys-XXXX-ptyN-to-master`.
+///
+/// Kept free of `cfg(windows)`, unlike its only caller, so the pattern logic
+/// can be unit tested on any host.
+fn is_msys_pty_name(name: &str) -> bool {
+    (name.contains("msys-") || name.contains("cygwin-")) && name.contains("-pty")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_msys_pty_name;
+
+    #[test]
+    fn matches_msys_pipe_names() {
+        assert!(is_msys_pty_name(r"\msys-1234-pty0-to-master"));
+        assert!(is_msys_pty_name(r"\msys-5678-pty3-from-master"));
+    }
+
+    #[test]
+    fn matches_cygwin_pipe_names() {
+        assert!(is_msys_pty_name(r"\cygwin-1234-pty0-to-master"));
+        assert!(is_msys_pty_name(r"\cygwin-5678-pty3-from-master"));
+    }
+
+    #[test]
+    fn rejects_plain_pipe_names() {
+        assert!(!is_msys_pty_name(r"\some-random-pipe-name"));
+    }
+
+    #[test]
+    fn rejects_partial_matches() {
+        // Has "-pty" but neither vendor prefix.
+        assert!(!is_msys_pty_name(r"\mintty-1234-pty0-to-master"));
+        // Has the vendor prefix but no "-pty" segment.
+        assert!(!is_msys_pty_name(r"\msys-1234-to-master"));
+        assert!(!is_msys_pty_name(r"\cygwin-1234-to-master"));
+    }
+}
+
+#[cfg(not(windows))]
+pub(crate) fn is_terminal<T: std::io::IsTerminal>(stream: &T) -> bool {
+    stream.is_terminal()
+}
+
 #[derive(Clone)]
 pub struct IsTerminal;
 
@@ -15,7 +108,10 @@ impl Command for IsTerminal {
 
     fn signature(&self) -> Signature {
         Signature::build("is-terminal")
-            .input_output_type(Type::Nothing, Type::Bool)
+            .input_output_types(vec![
+                (Type::Nothing, Type::Bool),
+                (Type::Nothing, Type::Record(vec![])),
+            ])
             .switch("stdin", "Check if stdin is a terminal", Some('i'))
             .switch("stdout", "Check if stdout is a terminal", Some('o'))
             .switch("stderr", "Check if stderr is a terminal", Some('e'))
@@ -27,11 +123,23 @@ impl Command for IsTerminal {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: r#"Return "terminal attached" if standard input is attached to a terminal, and "no terminal" if not."#,
-            example: r#"if (is-terminal --stdin) { "terminal attached" } else { "no terminal" }"#,
-            result: Some(Value::test_string("terminal attached")),
-        }]
+        vec![
+            Example {
+                description: r#"Return "terminal attached" if standard input is attached to a terminal, and "no terminal" if not."#,
+                example: r#"if (is-terminal --stdin) { "terminal attached" } else { "no terminal" }"#,
+                result: Some(Value::test_string("terminal attached")),
+            },
+            Example {
+                description: "Get the terminal status of all three standard streams at once.",
+                example: "is-terminal",
+                result: None,
+            },
+            Example {
+                description: "Get the terminal status of just stdin and stdout, as a record.",
+                example: "is-terminal --stdin --stdout",
+                result: None,
+            },
+        ]
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -49,30 +157,39 @@ impl Command for IsTerminal {
         let stdout = call.has_flag("stdout");
         let stderr = call.has_flag("stderr");
 
-        let is_terminal = match (stdin, stdout, stderr) {
-            (true, false, false) => std::io::stdin().is_terminal(),
-            (false, true, false) => std::io::stdout().is_terminal(),
-            (false, false, true) => std::io::stderr().is_terminal(),
-            (false, false, false) => {
-                return Err(ShellError::MissingParameter {
-                    param_name: "one of --stdin, --stdout, --stderr".into(),
-                    span: call.head,
-                });
-            }
+        let value = match (stdin, stdout, stderr) {
+            (true, false, false) => Value::bool(is_terminal(&std::io::stdin()), call.head),
+            (false, true, false) => Value::bool(is_terminal(&std::io::stdout()), call.head),
+            (false, false, true) => Value::bool(is_terminal(&std::io::stderr()), call.head),
+            (false, false, false) => Value::record(
+                record! {
+                    "stdin" => Value::bool(is_terminal(&std::io::stdin()), call.head),
+                    "stdout" => Value::bool(is_terminal(&std::io::stdout()), call.head),
+                    "stderr" => Value::bool(is_terminal(&std::io::stderr()), call.head),
+                },
+                call.head,
+            ),
             _ => {
-                let spans: Vec<_> = call.arguments.iter().map(|arg| arg.span()).collect();
-                let span = span(&spans);
-
-                return Err(ShellError::IncompatibleParametersSingle {
-                    msg: "Only one stream may be checked".into(),
-                    span,
-                });
+                let mut record = Record::new();
+                if stdin {
+                    record.push("stdin", Value::bool(is_terminal(&std::io::stdin()), call.head));
+                }
+                if stdout {
+                    record.push(
+                        "stdout",
+                        Value::bool(is_terminal(&std::io::stdout()), call.head),
+                    );
+                }
+                if stderr {
+                    record.push(
+                        "stderr",
+                        Value::bool(is_terminal(&std::io::stderr()), call.head),
+                    );
+                }
+                Value::record(record, call.head)
             }
         };
 
-        Ok(PipelineData::Value(
-            Value::bool(is_terminal, call.head),
-            None,
-        ))
+        Ok(PipelineData::Value(value, None))
     }
 }
@@ -0,0 +1,5 @@
+mod is_terminal;
+mod term_query;
+
+pub use is_terminal::IsTerminal;
+pub use term_query::TermQuery;
@@ -0,0 +1,155 @@
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    record, Category, Example, PipelineData, ShellError, Signature, Type, Value,
+};
+
+use super::is_terminal::is_terminal;
+
+#[derive(Clone)]
+pub struct TermQuery;
+
+impl Command for TermQuery {
+    fn name(&self) -> &str {
+        "term query"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("term query")
+            .input_output_type(Type::Nothing, Type::Record(vec![]))
+            .category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "Query the current terminal for its shell, OS, tty, size, and color capabilities."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get a record describing the current terminal context.",
+            example: "term query",
+            result: None,
+        }]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["shell", "os", "tty", "size", "color", "capabilities"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let shell = detect_shell(|name| {
+            stack
+                .get_env_var(engine_state, name)
+                .and_then(|value| value.as_string().ok())
+        });
+        let (columns, rows) = terminal_size::terminal_size()
+            .map(|(w, h)| (w.0 as i64, h.0 as i64))
+            .unwrap_or((0, 0));
+
+        let stdout_is_terminal = is_terminal(&std::io::stdout());
+        let supports_color =
+            stdout_is_terminal && stack.get_env_var(engine_state, "NO_COLOR").is_none();
+
+        Ok(PipelineData::Value(
+            Value::record(
+                record! {
+                    "shell" => Value::string(shell, head),
+                    "os" => Value::string(std::env::consts::OS, head),
+                    "stdin" => Value::bool(is_terminal(&std::io::stdin()), head),
+                    "stdout" => Value::bool(stdout_is_terminal, head),
+                    "stderr" => Value::bool(is_terminal(&std::io::stderr()), head),
+                    "columns" => Value::int(columns, head),
+                    "rows" => Value::int(rows, head),
+                    "supports_color" => Value::bool(supports_color, head),
+                },
+                head,
+            ),
+            None,
+        ))
+    }
+}
+
+/// Figure out which shell nushell is running under by probing the
+/// environment variables that shell sets for itself, the same markers
+/// tooling that generates shell-specific prompts looks for.
+///
+/// `get_env` looks up an environment variable by name, returning its string
+/// value if set. Taking it as a closure (rather than an `EngineState`/`Stack`
+/// pair) keeps the precedence logic below testable without constructing an
+/// engine.
+///
+/// There's no environment variable that reliably distinguishes PowerShell
+/// Core (`pwsh`) from Windows PowerShell (`powershell.exe`) — both only set
+/// `PSModulePath`, and edition is exposed solely through the `$PSVersionTable`
+/// automatic variable, not the environment — so both are reported as
+/// `"powershell"`.
+fn detect_shell(get_env: impl Fn(&str) -> Option<String>) -> String {
+    if get_env("NU_VERSION").is_some() {
+        return "nushell".into();
+    }
+
+    if get_env("PSModulePath").is_some() {
+        return "powershell".into();
+    }
+
+    if get_env("MSYSTEM").is_some() {
+        return "msys".into();
+    }
+
+    if let Some(path) = get_env("SHELL") {
+        if let Some(name) = path.rsplit('/').next() {
+            return name.to_string();
+        }
+    }
+
+    "unknown".into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::detect_shell;
+    use std::collections::HashMap;
+
+    fn detect(vars: &[(&str, &str)]) -> String {
+        let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+        detect_shell(|name| vars.get(name).map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn detects_nushell_first() {
+        assert_eq!(
+            detect(&[("NU_VERSION", "0.90.0"), ("PSModulePath", "C:\\Modules")]),
+            "nushell"
+        );
+    }
+
+    #[test]
+    fn detects_powershell() {
+        assert_eq!(detect(&[("PSModulePath", "C:\\Modules")]), "powershell");
+    }
+
+    #[test]
+    fn detects_msys_before_shell() {
+        assert_eq!(
+            detect(&[("MSYSTEM", "MINGW64"), ("SHELL", "/usr/bin/bash")]),
+            "msys"
+        );
+    }
+
+    #[test]
+    fn detects_shell_from_shell_env() {
+        assert_eq!(detect(&[("SHELL", "/usr/bin/zsh")]), "zsh");
+    }
+
+    #[test]
+    fn falls_back_to_unknown() {
+        assert_eq!(detect(&[]), "unknown");
+    }
+}